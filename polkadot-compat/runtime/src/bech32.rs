@@ -0,0 +1,149 @@
+//! A `no_std` implementation of the BIP-173 bech32 encoding used by Cosmos
+//! SDK addresses, so [`crate::Pallet::link_cosmos_account`] can check a
+//! submitted address actually decodes and checksums rather than eyeballing
+//! its length.
+
+use sp_std::vec::Vec;
+
+const CHARSET: &[u8; 32] = b"qpzry9x8gf2tvdw0s3jn54khce6mua7l";
+const CHECKSUM_LEN: usize = 6;
+
+/// Reasons a bech32 string can fail to decode.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum Bech32Error {
+    /// No `1` separator between the human-readable part and the data part.
+    MissingSeparator,
+    /// The human-readable part is empty.
+    EmptyHrp,
+    /// A data-part character is outside the bech32 charset.
+    InvalidChar,
+    /// The data part is shorter than the checksum itself.
+    TooShort,
+    /// The checksum does not verify against the human-readable part.
+    InvalidChecksum,
+    /// The 5-bit groups did not regroup cleanly into 8-bit bytes.
+    InvalidPadding,
+}
+
+fn polymod(values: &[u8]) -> u32 {
+    const GEN: [u32; 5] = [0x3b6a57b2, 0x26508e6d, 0x1ea119fa, 0x3d4233dd, 0x2a1462b3];
+    let mut chk: u32 = 1;
+    for &v in values {
+        let top = chk >> 25;
+        chk = ((chk & 0x1ff_ffff) << 5) ^ (v as u32);
+        for (i, gen) in GEN.iter().enumerate() {
+            if (top >> i) & 1 == 1 {
+                chk ^= gen;
+            }
+        }
+    }
+    chk
+}
+
+fn hrp_expand(hrp: &[u8]) -> Vec<u8> {
+    let mut v = Vec::with_capacity(hrp.len() * 2 + 1);
+    v.extend(hrp.iter().map(|c| c >> 5));
+    v.push(0);
+    v.extend(hrp.iter().map(|c| c & 31));
+    v
+}
+
+fn verify_checksum(hrp: &[u8], data: &[u8]) -> bool {
+    let mut values = hrp_expand(hrp);
+    values.extend_from_slice(data);
+    polymod(&values) == 1
+}
+
+fn create_checksum(hrp: &[u8], data: &[u8]) -> [u8; CHECKSUM_LEN] {
+    let mut values = hrp_expand(hrp);
+    values.extend_from_slice(data);
+    values.extend_from_slice(&[0u8; CHECKSUM_LEN]);
+    let polymod = polymod(&values) ^ 1;
+    let mut checksum = [0u8; CHECKSUM_LEN];
+    for (i, byte) in checksum.iter_mut().enumerate() {
+        *byte = ((polymod >> (5 * (CHECKSUM_LEN - 1 - i))) & 31) as u8;
+    }
+    checksum
+}
+
+/// Regroups a bitstream between `from_bits`-wide and `to_bits`-wide groups,
+/// as used to convert between 8-bit address bytes and 5-bit bech32 symbols.
+fn convert_bits(data: &[u8], from_bits: u32, to_bits: u32, pad: bool) -> Option<Vec<u8>> {
+    let mut acc: u32 = 0;
+    let mut bits: u32 = 0;
+    let mut out = Vec::new();
+    let max_value = (1u32 << to_bits) - 1;
+    for &value in data {
+        if (value as u32) >> from_bits != 0 {
+            return None;
+        }
+        acc = (acc << from_bits) | value as u32;
+        bits += from_bits;
+        while bits >= to_bits {
+            bits -= to_bits;
+            out.push(((acc >> bits) & max_value) as u8);
+        }
+    }
+    if pad {
+        if bits > 0 {
+            out.push(((acc << (to_bits - bits)) & max_value) as u8);
+        }
+    } else if bits >= from_bits || ((acc << (to_bits - bits)) & max_value) != 0 {
+        return None;
+    }
+    Some(out)
+}
+
+/// Decodes a bech32 string into its human-readable part and data payload
+/// (already regrouped from 5-bit symbols back into bytes).
+pub fn decode(input: &[u8]) -> Result<(Vec<u8>, Vec<u8>), Bech32Error> {
+    let separator = input
+        .iter()
+        .rposition(|&c| c == b'1')
+        .ok_or(Bech32Error::MissingSeparator)?;
+    if separator == 0 {
+        return Err(Bech32Error::EmptyHrp);
+    }
+
+    let hrp: Vec<u8> = input[..separator].iter().map(|c| c.to_ascii_lowercase()).collect();
+    let data_part = &input[separator + 1..];
+    if data_part.len() < CHECKSUM_LEN {
+        return Err(Bech32Error::TooShort);
+    }
+
+    let mut values = Vec::with_capacity(data_part.len());
+    for &c in data_part {
+        let v = CHARSET
+            .iter()
+            .position(|&x| x == c.to_ascii_lowercase())
+            .ok_or(Bech32Error::InvalidChar)?;
+        values.push(v as u8);
+    }
+
+    if !verify_checksum(&hrp, &values) {
+        return Err(Bech32Error::InvalidChecksum);
+    }
+
+    let program = convert_bits(&values[..values.len() - CHECKSUM_LEN], 5, 8, false)
+        .ok_or(Bech32Error::InvalidPadding)?;
+    Ok((hrp, program))
+}
+
+/// Encodes `program` bytes as a bech32 string with the given human-readable
+/// part (e.g. `b"cosmos"`).
+pub fn encode(hrp: &[u8], program: &[u8]) -> Result<Vec<u8>, Bech32Error> {
+    let five_bit = convert_bits(program, 8, 5, true).ok_or(Bech32Error::InvalidPadding)?;
+    let checksum = create_checksum(hrp, &five_bit);
+
+    let mut out = Vec::with_capacity(hrp.len() + 1 + five_bit.len() + CHECKSUM_LEN);
+    out.extend_from_slice(hrp);
+    out.push(b'1');
+    out.extend(five_bit.iter().map(|&v| CHARSET[v as usize]));
+    out.extend(checksum.iter().map(|&v| CHARSET[v as usize]));
+    Ok(out)
+}
+
+/// Returns `true` if `address` is a well-formed, checksummed bech32 string.
+pub fn is_valid(address: &[u8]) -> bool {
+    decode(address).is_ok()
+}
@@ -0,0 +1,102 @@
+//! A `SignedExtension` that rejects obsolete completion submissions before
+//! they reach the transaction pool, following the `check_obsolete_extension`
+//! pattern used by the bridge-hub runtimes: a relayer resubmitting a
+//! completion for an already-completed transfer is dropped for free instead
+//! of paying weight to re-discover that fact in the dispatchable itself.
+
+use crate::pallet::{Call, Config, CosmosAccounts, CrossChainTransactions, TxStatus};
+use frame_support::{
+    codec::{Decode, Encode},
+    dispatch::DispatchInfo,
+    traits::IsSubType,
+    RuntimeDebug,
+};
+use scale_info::TypeInfo;
+use sp_runtime::{
+    traits::{DispatchInfoOf, Dispatchable, Hash, SignedExtension},
+    transaction_validity::{InvalidTransaction, TransactionValidity, TransactionValidityError, ValidTransaction},
+};
+use sp_std::marker::PhantomData;
+
+/// Rejects `complete_cross_chain_tx` and `complete_cross_chain_asset_tx`
+/// calls whose transaction is already completed, so duplicate relay
+/// submissions are dropped from the pool instead of consuming weight.
+#[derive(Encode, Decode, Clone, Eq, PartialEq, RuntimeDebug, TypeInfo)]
+#[scale_info(skip_type_params(T))]
+pub struct CheckObsoleteCompleteCrossChainTx<T: Config + Send + Sync>(PhantomData<T>);
+
+impl<T: Config + Send + Sync> CheckObsoleteCompleteCrossChainTx<T> {
+    pub fn new() -> Self {
+        Self(PhantomData)
+    }
+}
+
+impl<T: Config + Send + Sync> Default for CheckObsoleteCompleteCrossChainTx<T> {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+impl<T: Config + Send + Sync> SignedExtension for CheckObsoleteCompleteCrossChainTx<T>
+where
+    T::RuntimeCall: Dispatchable<Info = DispatchInfo> + IsSubType<Call<T>>,
+{
+    const IDENTIFIER: &'static str = "CheckObsoleteCompleteCrossChainTx";
+    type AccountId = T::AccountId;
+    type Call = T::RuntimeCall;
+    type AdditionalSigned = ();
+    type Pre = ();
+
+    fn additional_signed(&self) -> Result<(), TransactionValidityError> {
+        Ok(())
+    }
+
+    fn validate(
+        &self,
+        _who: &Self::AccountId,
+        call: &Self::Call,
+        _info: &DispatchInfoOf<Self::Call>,
+        _len: usize,
+    ) -> TransactionValidity {
+        match call.is_sub_type() {
+            Some(Call::complete_cross_chain_tx { tx_hash, .. }) => {
+                if let Some(tx) = CrossChainTransactions::<T>::get(tx_hash) {
+                    if tx.status == TxStatus::Completed {
+                        return Err(InvalidTransaction::Stale.into());
+                    }
+                }
+            }
+            Some(Call::complete_cross_chain_asset_tx {
+                from_cosmos_address,
+                denom,
+                amount,
+                sequence,
+                ..
+            }) => {
+                // Inbound asset completions never start `Initiated`; a
+                // record existing at all means this transfer already
+                // completed, so resolve the same `tx_hash` the dispatchable
+                // derives and check for its presence.
+                if let Some(to) = CosmosAccounts::<T>::get(from_cosmos_address) {
+                    let tx_hash =
+                        T::Hashing::hash_of(&(from_cosmos_address, denom, amount, sequence, &to));
+                    if CrossChainTransactions::<T>::contains_key(&tx_hash) {
+                        return Err(InvalidTransaction::Stale.into());
+                    }
+                }
+            }
+            _ => {}
+        }
+        Ok(ValidTransaction::default())
+    }
+
+    fn pre_dispatch(
+        self,
+        who: &Self::AccountId,
+        call: &Self::Call,
+        info: &DispatchInfoOf<Self::Call>,
+        len: usize,
+    ) -> Result<Self::Pre, TransactionValidityError> {
+        self.validate(who, call, info, len).map(|_| ())
+    }
+}
@@ -0,0 +1,120 @@
+//! ICS-23 vector-commitment (Merkle) proof verification.
+//!
+//! Implements just enough of the ICS-23 existence-proof spec to check that a
+//! key/value pair is present under a trusted root (the light client's
+//! `app_hash`): a leaf op describing how the leaf hash is built, followed by
+//! an ordered chain of inner nodes folded up to the root.
+
+use frame_support::codec::{Decode, Encode};
+use frame_support::RuntimeDebug;
+use scale_info::TypeInfo;
+use sp_std::vec::Vec;
+
+/// Hash functions supported by a `LeafOp`/`InnerOp`.
+#[derive(Encode, Decode, Clone, Copy, PartialEq, Eq, RuntimeDebug, TypeInfo)]
+pub enum HashOp {
+    Sha256,
+    Blake2b256,
+}
+
+fn apply_hash(op: HashOp, data: &[u8]) -> Vec<u8> {
+    match op {
+        HashOp::Sha256 => sp_io::hashing::sha2_256(data).to_vec(),
+        HashOp::Blake2b256 => sp_io::hashing::blake2_256(data).to_vec(),
+    }
+}
+
+/// How a length is prefixed before the bytes it measures.
+#[derive(Encode, Decode, Clone, Copy, PartialEq, Eq, RuntimeDebug, TypeInfo)]
+pub enum LengthOp {
+    /// No length prefix.
+    NoPrefix,
+    /// A protobuf-style varint length prefix.
+    VarProto,
+}
+
+fn encode_length(op: LengthOp, data: &[u8]) -> Vec<u8> {
+    match op {
+        LengthOp::NoPrefix => Vec::new(),
+        LengthOp::VarProto => {
+            let mut len = data.len() as u64;
+            let mut out = Vec::new();
+            loop {
+                let mut byte = (len & 0x7f) as u8;
+                len >>= 7;
+                if len != 0 {
+                    byte |= 0x80;
+                }
+                out.push(byte);
+                if len == 0 {
+                    break;
+                }
+            }
+            out
+        }
+    }
+}
+
+/// Describes how to fold a key/value pair into the leaf hash of the proof.
+#[derive(Encode, Decode, Clone, PartialEq, Eq, RuntimeDebug, TypeInfo)]
+pub struct LeafOp {
+    pub hash: HashOp,
+    pub length: LengthOp,
+    /// A fixed prefix byte string distinguishing leaves from inner nodes.
+    pub prefix: Vec<u8>,
+    pub key: Vec<u8>,
+    pub value: Vec<u8>,
+}
+
+/// A single step up the Merkle tree from a child hash to its parent.
+#[derive(Encode, Decode, Clone, PartialEq, Eq, RuntimeDebug, TypeInfo)]
+pub struct InnerOp {
+    pub prefix: Vec<u8>,
+    pub suffix: Vec<u8>,
+}
+
+/// An ICS-23 existence proof: a leaf plus the inner nodes from the leaf up to
+/// the root.
+#[derive(Encode, Decode, Clone, PartialEq, Eq, RuntimeDebug, TypeInfo)]
+pub struct ExistenceProof {
+    pub leaf: LeafOp,
+    pub path: Vec<InnerOp>,
+}
+
+/// Reasons a membership proof can fail to verify.
+#[derive(Encode, Decode, Clone, Copy, PartialEq, Eq, RuntimeDebug, TypeInfo)]
+pub enum Ics23Error {
+    /// The proof does not fold up to the expected root.
+    RootMismatch,
+}
+
+/// Verifies that `proof.leaf.{key,value}` is present under `root`.
+///
+/// The leaf hash is `hash(prefix || len(key) || key || len(hash(value)) ||
+/// hash(value))`, and each inner node folds `h = hash(node.prefix || h ||
+/// node.suffix)` up to the root.
+pub fn verify_membership(proof: &ExistenceProof, root: &[u8]) -> Result<(), Ics23Error> {
+    let leaf = &proof.leaf;
+
+    let mut leaf_preimage = leaf.prefix.clone();
+    leaf_preimage.extend(encode_length(leaf.length, &leaf.key));
+    leaf_preimage.extend(&leaf.key);
+    let hashed_value = apply_hash(leaf.hash, &leaf.value);
+    leaf_preimage.extend(encode_length(leaf.length, &hashed_value));
+    leaf_preimage.extend(&hashed_value);
+
+    let mut running = apply_hash(leaf.hash, &leaf_preimage);
+
+    for inner in &proof.path {
+        let mut preimage = inner.prefix.clone();
+        preimage.extend(&running);
+        preimage.extend(&inner.suffix);
+        running = apply_hash(leaf.hash, &preimage);
+    }
+
+    if running == root {
+        Ok(())
+    } else {
+        Err(Ics23Error::RootMismatch)
+    }
+}
@@ -0,0 +1,286 @@
+//! Tendermint light-client verification for the Cosmos bridge.
+//!
+//! This module is deliberately free of any `Config`/storage coupling so the
+//! verification rules can be exercised and reasoned about on their own. The
+//! pallet wires these pure functions into the `submit_header` extrinsic and
+//! owns the trusted state in storage.
+
+use frame_support::codec::{Decode, Encode};
+use frame_support::RuntimeDebug;
+use scale_info::TypeInfo;
+use sp_core::ed25519::{Public, Signature};
+use sp_std::collections::btree_set::BTreeSet;
+use sp_std::vec::Vec;
+
+/// Block height of a Cosmos header.
+pub type Height = u64;
+
+/// Unix timestamp, in seconds.
+pub type Timestamp = u64;
+
+/// The subset of Tendermint consensus state this light client needs in order
+/// to verify subsequent headers and, later, ICS-23 membership proofs against
+/// `app_hash`.
+#[derive(Encode, Decode, Clone, PartialEq, Eq, RuntimeDebug, TypeInfo)]
+pub struct ConsensusState {
+    pub height: Height,
+    pub time: Timestamp,
+    pub next_validators_hash: Vec<u8>,
+    pub app_hash: Vec<u8>,
+}
+
+/// A single validator in a Tendermint validator set.
+#[derive(Encode, Decode, Clone, PartialEq, Eq, RuntimeDebug, TypeInfo)]
+pub struct Validator {
+    pub pub_key: [u8; 32],
+    pub voting_power: u64,
+}
+
+/// A Tendermint validator set, together with its canonical hash as referenced
+/// by headers (`validators_hash` / `next_validators_hash`).
+#[derive(Encode, Decode, Clone, PartialEq, Eq, RuntimeDebug, TypeInfo)]
+pub struct ValidatorSet {
+    pub validators: Vec<Validator>,
+    pub hash: Vec<u8>,
+}
+
+/// The header fields needed to verify a consensus state transition.
+#[derive(Encode, Decode, Clone, PartialEq, Eq, RuntimeDebug, TypeInfo)]
+pub struct TendermintHeader {
+    pub height: Height,
+    pub time: Timestamp,
+    pub validators_hash: Vec<u8>,
+    pub next_validators_hash: Vec<u8>,
+    pub app_hash: Vec<u8>,
+}
+
+/// A single precommit vote included in a commit.
+#[derive(Encode, Decode, Clone, PartialEq, Eq, RuntimeDebug, TypeInfo)]
+pub struct CommitSig {
+    pub validator_pub_key: [u8; 32],
+    pub signature: [u8; 64],
+}
+
+/// The set of precommit votes backing a header.
+#[derive(Encode, Decode, Clone, PartialEq, Eq, RuntimeDebug, TypeInfo)]
+pub struct Commit {
+    pub signatures: Vec<CommitSig>,
+}
+
+/// Reasons a header update can be rejected.
+#[derive(Encode, Decode, Clone, Copy, PartialEq, Eq, RuntimeDebug, TypeInfo)]
+pub enum VerifyError {
+    /// The trusted consensus state has aged out of its trusting period.
+    TrustedStateExpired,
+    /// The new header is not newer than the trusted state.
+    HeaderNotMonotonic,
+    /// An adjacent update's `validators_hash` does not match the trusted
+    /// `next_validators_hash`, or the submitted `ValidatorSet`'s recomputed
+    /// hash does not match the header's `validators_hash`.
+    ValidatorSetMismatch,
+    /// The submitted `trusted_validators` set's recomputed hash does not
+    /// match the trusted consensus state's `next_validators_hash`.
+    TrustedValidatorSetMismatch,
+    /// The signers common to the trusted validator set do not hold more than
+    /// 1/3 of the trusted voting power (trust level not met).
+    InsufficientTrustedVotingPower,
+    /// The commit does not hold more than 2/3 of the new validator set's
+    /// voting power.
+    InsufficientNewVotingPower,
+}
+
+/// Recomputes a validator set's canonical hash from its members, so headers
+/// can be bound to the actual validators supplied rather than trusting a
+/// caller-provided `ValidatorSet.hash` field.
+fn compute_validator_set_hash(set: &ValidatorSet) -> Vec<u8> {
+    sp_io::hashing::blake2_256(&set.validators.encode()).to_vec()
+}
+
+/// Returns the total voting power of a validator set.
+pub fn total_voting_power(set: &ValidatorSet) -> u64 {
+    set.validators
+        .iter()
+        .fold(0u64, |acc, v| acc.saturating_add(v.voting_power))
+}
+
+/// Hashes a header to the bytes that inner commit signatures are expected to
+/// cover alongside the chain ID and height.
+fn header_hash(header: &TendermintHeader) -> [u8; 32] {
+    sp_io::hashing::blake2_256(&header.encode())
+}
+
+/// Builds the canonical vote bytes a validator signs over for a given header.
+fn canonical_vote_bytes(chain_id: &[u8], height: Height, block_hash: &[u8]) -> Vec<u8> {
+    let mut buf = Vec::with_capacity(chain_id.len() + 8 + block_hash.len());
+    buf.extend_from_slice(chain_id);
+    buf.extend_from_slice(&height.to_be_bytes());
+    buf.extend_from_slice(block_hash);
+    buf
+}
+
+/// Sums the voting power of `set` members whose signature over `vote_msg`
+/// verifies, crediting each validator at most once — a commit repeating one
+/// validator's signature must not let that validator's power be counted
+/// twice.
+fn tally_voting_power(commit: &Commit, set: &ValidatorSet, vote_msg: &[u8]) -> u64 {
+    let mut seen_signers = BTreeSet::new();
+    commit
+        .signatures
+        .iter()
+        .filter_map(|sig| {
+            if !seen_signers.insert(sig.validator_pub_key) {
+                return None;
+            }
+            set.validators
+                .iter()
+                .find(|v| v.pub_key == sig.validator_pub_key)
+                .filter(|v| {
+                    sp_io::crypto::ed25519_verify(
+                        &Signature::from_raw(sig.signature),
+                        vote_msg,
+                        &Public::from_raw(v.pub_key),
+                    )
+                })
+                .map(|v| v.voting_power)
+        })
+        .fold(0u64, |acc, power| acc.saturating_add(power))
+}
+
+/// Sums, in terms of `trusted_validators` power, the voting power of signers
+/// that both verify against `new_set` and are members of `trusted_validators`,
+/// crediting each validator at most once.
+fn tally_trusted_overlap_power(
+    commit: &Commit,
+    trusted_validators: &ValidatorSet,
+    new_set: &ValidatorSet,
+    vote_msg: &[u8],
+) -> u64 {
+    let mut seen_signers = BTreeSet::new();
+    commit
+        .signatures
+        .iter()
+        .filter_map(|sig| {
+            if !seen_signers.insert(sig.validator_pub_key) {
+                return None;
+            }
+            let trusted = trusted_validators
+                .validators
+                .iter()
+                .find(|v| v.pub_key == sig.validator_pub_key)?;
+            let signer = new_set
+                .validators
+                .iter()
+                .find(|v| v.pub_key == sig.validator_pub_key)?;
+            sp_io::crypto::ed25519_verify(
+                &Signature::from_raw(sig.signature),
+                vote_msg,
+                &Public::from_raw(signer.pub_key),
+            )
+            .then_some(trusted.voting_power)
+        })
+        .fold(0u64, |acc, power| acc.saturating_add(power))
+}
+
+fn ensure_more_than_two_thirds(signed: u64, total: u64) -> Result<(), VerifyError> {
+    if (signed as u128).saturating_mul(3) > (total as u128).saturating_mul(2) {
+        Ok(())
+    } else {
+        Err(VerifyError::InsufficientNewVotingPower)
+    }
+}
+
+fn ensure_more_than_one_third(signed: u64, total: u64) -> Result<(), VerifyError> {
+    if (signed as u128).saturating_mul(3) > total as u128 {
+        Ok(())
+    } else {
+        Err(VerifyError::InsufficientTrustedVotingPower)
+    }
+}
+
+fn check_trusting_period(
+    trusted: &ConsensusState,
+    header: &TendermintHeader,
+    trusting_period: Timestamp,
+    now: Timestamp,
+) -> Result<(), VerifyError> {
+    if trusted.time.saturating_add(trusting_period) < now {
+        return Err(VerifyError::TrustedStateExpired);
+    }
+    if header.height <= trusted.height || header.time <= trusted.time {
+        return Err(VerifyError::HeaderNotMonotonic);
+    }
+    Ok(())
+}
+
+/// Verifies a header for the adjacent case (`header.height == trusted.height + 1`):
+/// the header's `validators_hash` must equal the trusted `next_validators_hash`,
+/// and the commit must hold more than 2/3 of the new set's voting power.
+pub fn verify_adjacent(
+    trusted: &ConsensusState,
+    header: &TendermintHeader,
+    commit: &Commit,
+    validator_set: &ValidatorSet,
+    trusting_period: Timestamp,
+    now: Timestamp,
+    chain_id: &[u8],
+) -> Result<ConsensusState, VerifyError> {
+    check_trusting_period(trusted, header, trusting_period, now)?;
+
+    if header.validators_hash != trusted.next_validators_hash {
+        return Err(VerifyError::ValidatorSetMismatch);
+    }
+    if compute_validator_set_hash(validator_set) != header.validators_hash {
+        return Err(VerifyError::ValidatorSetMismatch);
+    }
+
+    let vote_msg = canonical_vote_bytes(chain_id, header.height, &header_hash(header));
+    let signed = tally_voting_power(commit, validator_set, &vote_msg);
+    ensure_more_than_two_thirds(signed, total_voting_power(validator_set))?;
+
+    Ok(ConsensusState {
+        height: header.height,
+        time: header.time,
+        next_validators_hash: header.next_validators_hash.clone(),
+        app_hash: header.app_hash.clone(),
+    })
+}
+
+/// Verifies a header for the skipping case (`header.height > trusted.height + 1`):
+/// validators common to the trusted set who signed must hold more than 1/3 of
+/// the trusted voting power (the trust level), and the commit must separately
+/// hold more than 2/3 of the new set's voting power.
+pub fn verify_skipping(
+    trusted: &ConsensusState,
+    trusted_validators: &ValidatorSet,
+    header: &TendermintHeader,
+    commit: &Commit,
+    validator_set: &ValidatorSet,
+    trusting_period: Timestamp,
+    now: Timestamp,
+    chain_id: &[u8],
+) -> Result<ConsensusState, VerifyError> {
+    check_trusting_period(trusted, header, trusting_period, now)?;
+
+    if compute_validator_set_hash(trusted_validators) != trusted.next_validators_hash {
+        return Err(VerifyError::TrustedValidatorSetMismatch);
+    }
+    if compute_validator_set_hash(validator_set) != header.validators_hash {
+        return Err(VerifyError::ValidatorSetMismatch);
+    }
+
+    let vote_msg = canonical_vote_bytes(chain_id, header.height, &header_hash(header));
+
+    let trust_level_power =
+        tally_trusted_overlap_power(commit, trusted_validators, validator_set, &vote_msg);
+    ensure_more_than_one_third(trust_level_power, total_voting_power(trusted_validators))?;
+
+    let signed = tally_voting_power(commit, validator_set, &vote_msg);
+    ensure_more_than_two_thirds(signed, total_voting_power(validator_set))?;
+
+    Ok(ConsensusState {
+        height: header.height,
+        time: header.time,
+        next_validators_hash: header.next_validators_hash.clone(),
+        app_hash: header.app_hash.clone(),
+    })
+}
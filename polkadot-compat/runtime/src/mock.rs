@@ -0,0 +1,586 @@
+//! An `xcm-simulator` network of a relay chain and two parachains — the
+//! Cosmos-bridge parachain and a destination parachain — used to prove that
+//! a verified inbound Cosmos transfer routed with a `dest` actually lands as
+//! a balance on the destination chain.
+//!
+//! This mirrors the `xcm-simulator/example` layout: a tiny `mock_msg_queue`
+//! pallet stands in for the real XCMP/DMP transport, and `decl_test_network!`
+//! wires the chains' message queues together.
+
+use frame_support::{
+    construct_runtime, parameter_types,
+    traits::{ConstU32, Everything, Nothing},
+    weights::Weight,
+};
+use sp_core::H256;
+use sp_runtime::{traits::IdentityLookup, AccountId32};
+use xcm::latest::prelude::*;
+use xcm_builder::{
+    AccountId32Aliases, AllowUnpaidExecutionFrom, FixedRateOfFungible, FixedWeightBounds,
+    FungibleAdapter, FungiblesAdapter, IsConcrete, NativeAsset, NoChecking, ParentIsPreset,
+    SiblingParachainConvertsVia, SignedAccountId32AsNative, SovereignSignedViaLocation,
+};
+use xcm_executor::traits::{Error as MatchError, MatchesFungibles};
+use xcm_executor::{Config as XcmExecutorConfig, XcmExecutor};
+use xcm_simulator::{decl_test_network, decl_test_parachain, decl_test_relay_chain};
+
+pub type AccountId = AccountId32;
+pub type Balance = u128;
+pub type AssetId = u32;
+
+pub const ALICE: AccountId = AccountId32::new([1u8; 32]);
+pub const INITIAL_BALANCE: Balance = 1_000_000_000;
+pub const BRIDGE_PARA_ID: u32 = 1;
+pub const DESTINATION_PARA_ID: u32 = 2;
+
+/// Stands in for `cumulus-pallet-parachain-system`, queuing inbound XCMP/DMP
+/// messages for the executor to run in the test network.
+#[frame_support::pallet]
+pub mod mock_msg_queue {
+    use super::*;
+    use frame_support::pallet_prelude::*;
+    use xcm::latest::Xcm;
+    use xcm_executor::XcmExecutor;
+
+    #[pallet::config]
+    pub trait Config: frame_system::Config {
+        type RuntimeEvent: From<Event<Self>> + IsType<<Self as frame_system::Config>::RuntimeEvent>;
+        type XcmExecutor: xcm_executor::traits::ExecuteXcm<Self::RuntimeCall>;
+    }
+
+    #[pallet::pallet]
+    pub struct Pallet<T>(_);
+
+    #[pallet::storage]
+    pub type ParachainId<T> = StorageValue<_, u32, ValueQuery>;
+
+    #[pallet::event]
+    #[pallet::generate_deposit(pub(super) fn deposit_event)]
+    pub enum Event<T: Config> {
+        Success(Option<T::Hash>),
+        Fail(Option<T::Hash>),
+    }
+
+    impl<T: Config> Pallet<T> {
+        pub fn set_para_id(para_id: u32) {
+            ParachainId::<T>::put(para_id);
+        }
+
+        fn handle_xcm_message(message: Xcm<T::RuntimeCall>) {
+            let hash = Encode::using_encoded(&message, sp_io::hashing::blake2_256);
+            let outcome = T::XcmExecutor::execute_xcm(Parent, message, hash, Weight::max_value());
+            match outcome.ensure_complete() {
+                Ok(_) => Self::deposit_event(Event::Success(None)),
+                Err(_) => Self::deposit_event(Event::Fail(None)),
+            }
+        }
+    }
+
+    impl<T: Config> cumulus_primitives_core::XcmpMessageHandler for Pallet<T> {
+        fn handle_xcmp_messages<'a, I: Iterator<Item = (cumulus_primitives_core::ParaId, sp_runtime::BlockNumber, &'a [u8])>>(
+            iter: I,
+            _max_weight: Weight,
+        ) -> Weight {
+            for (_sender, _block, mut data) in iter {
+                if let Ok(message) = Xcm::<T::RuntimeCall>::decode(&mut data) {
+                    Self::handle_xcm_message(message);
+                }
+            }
+            Weight::zero()
+        }
+    }
+
+    impl<T: Config> cumulus_primitives_core::DmpMessageHandler for Pallet<T> {
+        fn handle_dmp_messages(
+            iter: impl Iterator<Item = (sp_runtime::BlockNumber, sp_std::vec::Vec<u8>)>,
+            _max_weight: Weight,
+        ) -> Weight {
+            for (_block, mut data) in iter {
+                if let Ok(message) = Xcm::<T::RuntimeCall>::decode(&mut data.as_slice()) {
+                    Self::handle_xcm_message(message);
+                }
+            }
+            Weight::zero()
+        }
+    }
+}
+
+/// Matches the concrete `(0, X1(GeneralIndex(id)))` locations produced by
+/// `AssetIdToMultiLocation` against the local `pallet_assets` `AssetId`, so
+/// `FungiblesAdapter` can actually deposit a forwarded, denom-mapped asset
+/// rather than only `LocalAssetTransactor` recognising the native token.
+pub struct MatchBridgedAssetId;
+impl MatchesFungibles<AssetId, Balance> for MatchBridgedAssetId {
+    fn matches_fungibles(asset: &MultiAsset) -> Result<(AssetId, Balance), MatchError> {
+        let amount = match asset.fun {
+            Fungible(amount) => amount,
+            _ => return Err(MatchError::AssetNotHandled),
+        };
+        match asset.id {
+            Concrete(MultiLocation {
+                parents: 0,
+                interior: X1(GeneralIndex(id)),
+            }) => Ok((id as AssetId, amount)),
+            _ => Err(MatchError::AssetNotHandled),
+        }
+    }
+}
+
+/// Shared config across both parachains in the network: system, balances,
+/// and the XCM executor/sender plumbing.
+macro_rules! impl_parachain_runtime {
+    ($assets_config:item) => {
+        construct_runtime!(
+            pub enum Runtime {
+                System: frame_system,
+                Balances: pallet_balances,
+                Assets: pallet_assets,
+                XcmPallet: pallet_xcm,
+                MsgQueue: mock_msg_queue,
+                CosmosBridge: cosmos_pallet,
+            }
+        );
+
+        parameter_types! {
+            pub const BlockHashCount: u64 = 250;
+            pub RelayLocation: MultiLocation = MultiLocation::parent();
+            pub UnitWeightCost: Weight = Weight::from_parts(1_000, 1_000);
+            pub const MaxInstructions: u32 = 100;
+        }
+
+        impl frame_system::Config for Runtime {
+            type BaseCallFilter = Everything;
+            type BlockWeights = ();
+            type BlockLength = ();
+            type RuntimeOrigin = RuntimeOrigin;
+            type RuntimeCall = RuntimeCall;
+            type Nonce = u64;
+            type Hash = H256;
+            type Hashing = sp_runtime::traits::BlakeTwo256;
+            type AccountId = AccountId;
+            type Lookup = IdentityLookup<AccountId>;
+            type Block = frame_system::mocking::MockBlock<Runtime>;
+            type RuntimeEvent = RuntimeEvent;
+            type BlockHashCount = BlockHashCount;
+            type DbWeight = ();
+            type Version = ();
+            type PalletInfo = PalletInfo;
+            type OnNewAccount = ();
+            type OnKilledAccount = ();
+            type AccountData = pallet_balances::AccountData<Balance>;
+            type SystemWeightInfo = ();
+            type SS58Prefix = ();
+            type OnSetCode = ();
+            type MaxConsumers = ConstU32<16>;
+        }
+
+        impl pallet_balances::Config for Runtime {
+            type MaxLocks = ConstU32<50>;
+            type MaxReserves = ConstU32<50>;
+            type ReserveIdentifier = [u8; 8];
+            type Balance = Balance;
+            type RuntimeEvent = RuntimeEvent;
+            type DustRemoval = ();
+            type ExistentialDeposit = ConstU32<1>;
+            type AccountStore = System;
+            type WeightInfo = ();
+            type FreezeIdentifier = ();
+            type MaxFreezes = ConstU32<0>;
+            type RuntimeHoldReason = RuntimeHoldReason;
+        }
+
+        $assets_config
+
+        pub type LocationToAccountId = (
+            ParentIsPreset<AccountId>,
+            SiblingParachainConvertsVia<polkadot_parachain_primitives::primitives::Sibling, AccountId>,
+            AccountId32Aliases<RelayLocation, AccountId>,
+        );
+
+        pub type LocalNativeTransactor =
+            FungibleAdapter<Balances, IsConcrete<RelayLocation>, LocationToAccountId, AccountId, ()>;
+
+        /// Deposits/withdraws denom-mapped, non-native assets into
+        /// `pallet_assets` — without this, an inbound transfer forwarded as
+        /// `ReserveAssetDeposited`/`DepositAsset` has no transactor able to
+        /// handle it and silently fails to land.
+        pub type LocalFungiblesTransactor = FungiblesAdapter<
+            Assets,
+            MatchBridgedAssetId,
+            LocationToAccountId,
+            AccountId,
+            NoChecking,
+            (),
+        >;
+
+        /// Tried in order: the native token first, then denom-mapped assets.
+        pub type LocalAssetTransactor = (LocalNativeTransactor, LocalFungiblesTransactor);
+
+        pub type XcmOriginToCallOrigin = (
+            SovereignSignedViaLocation<LocationToAccountId, RuntimeOrigin>,
+            SignedAccountId32AsNative<RelayLocation, RuntimeOrigin>,
+        );
+
+        pub type XcmRouter = xcm_simulator::ParachainXcmRouter<MsgQueue>;
+
+        pub struct XcmConfig;
+        impl XcmExecutorConfig for XcmConfig {
+            type RuntimeCall = RuntimeCall;
+            type XcmSender = XcmRouter;
+            type AssetTransactor = LocalAssetTransactor;
+            type OriginConverter = XcmOriginToCallOrigin;
+            type IsReserve = NativeAsset;
+            type IsTeleporter = ();
+            type UniversalLocation = xcm_builder::Parentage;
+            type Barrier = AllowUnpaidExecutionFrom<Everything>;
+            type Weigher = FixedWeightBounds<UnitWeightCost, RuntimeCall, MaxInstructions>;
+            type Trader = FixedRateOfFungible<RelayLocation, frame_support::traits::ConstU128<0>>;
+            type ResponseHandler = ();
+            type AssetTrap = ();
+            type AssetClaims = ();
+            type SubscriptionService = ();
+            type PalletInstancesInfo = ();
+            type MaxAssetsIntoHolding = ConstU32<64>;
+            type FeeManager = ();
+            type MessageExporter = ();
+            type UniversalAliases = Nothing;
+            type CallDispatcher = RuntimeCall;
+            type SafeCallFilter = Everything;
+            type Aliasers = Nothing;
+        }
+
+        impl pallet_xcm::Config for Runtime {
+            type RuntimeEvent = RuntimeEvent;
+            type SendXcmOrigin = xcm_builder::EnsureXcmOrigin<RuntimeOrigin, ()>;
+            type XcmRouter = XcmRouter;
+            type ExecuteXcmOrigin = xcm_builder::EnsureXcmOrigin<RuntimeOrigin, ()>;
+            type XcmExecuteFilter = Nothing;
+            type XcmExecutor = XcmExecutor<XcmConfig>;
+            type XcmTeleportFilter = Everything;
+            type XcmReserveTransferFilter = Everything;
+            type Weigher = FixedWeightBounds<UnitWeightCost, RuntimeCall, MaxInstructions>;
+            type UniversalLocation = xcm_builder::Parentage;
+            type RuntimeOrigin = RuntimeOrigin;
+            type RuntimeCall = RuntimeCall;
+            const VERSION_DISCOVERY_QUEUE_SIZE: u32 = 100;
+            type AdvertisedXcmVersion = pallet_xcm::CurrentXcmVersion;
+            type Currency = Balances;
+            type CurrencyMatcher = ();
+            type TrustedLockers = ();
+            type SovereignAccountOf = LocationToAccountId;
+            type MaxLockers = ConstU32<8>;
+            type WeightInfo = pallet_xcm::TestWeightInfo;
+            type AdminOrigin = frame_system::EnsureRoot<AccountId>;
+            type MaxRemoteLockConsumers = ConstU32<0>;
+            type RemoteLockConsumerIdentifier = ();
+        }
+
+        impl mock_msg_queue::Config for Runtime {
+            type RuntimeEvent = RuntimeEvent;
+            type XcmExecutor = XcmExecutor<XcmConfig>;
+        }
+    };
+}
+
+/// The Cosmos-bridge parachain: runs `cosmos_pallet` and routes verified
+/// inbound transfers onward via `XcmSender`.
+pub mod bridge_parachain {
+    use super::*;
+    use crate as cosmos_pallet;
+
+    impl_parachain_runtime!(
+        impl pallet_assets::Config for Runtime {
+            type RuntimeEvent = RuntimeEvent;
+            type Balance = Balance;
+            type AssetId = AssetId;
+            type AssetIdParameter = AssetId;
+            type Currency = Balances;
+            type CreateOrigin =
+                frame_support::traits::AsEnsureOriginWithArg<frame_system::EnsureSigned<AccountId>>;
+            type ForceOrigin = frame_system::EnsureRoot<AccountId>;
+            type AssetDeposit = ConstU32<0>;
+            type AssetAccountDeposit = ConstU32<0>;
+            type MetadataDepositBase = ConstU32<0>;
+            type MetadataDepositPerByte = ConstU32<0>;
+            type ApprovalDeposit = ConstU32<0>;
+            type StringLimit = ConstU32<50>;
+            type Freezer = ();
+            type Extra = ();
+            type CallbackHandle = ();
+            type WeightInfo = ();
+            type RemoveItemsLimit = ConstU32<5>;
+        }
+    );
+
+    pub struct AssetIdToMultiLocation;
+    impl sp_runtime::traits::Convert<AssetId, MultiLocation> for AssetIdToMultiLocation {
+        fn convert(asset_id: AssetId) -> MultiLocation {
+            MultiLocation::new(0, X1(GeneralIndex(asset_id as u128)))
+        }
+    }
+
+    parameter_types! {
+        pub const BridgePalletId: frame_support::PalletId = frame_support::PalletId(*b"py/cosmb");
+        pub const BridgeFee: Balance = 0;
+        pub const RelayerReward: Balance = 0;
+        pub const TrustingPeriod: u64 = 7 * 24 * 60 * 60;
+    }
+
+    impl cosmos_pallet::Config for Runtime {
+        type RuntimeEvent = RuntimeEvent;
+        type Currency = Balances;
+        type TimeProvider = frame_system::Pallet<Runtime>;
+        type TrustingPeriod = TrustingPeriod;
+        type PalletId = BridgePalletId;
+        type BridgeFee = BridgeFee;
+        type RelayerReward = RelayerReward;
+        type Assets = Assets;
+        type XcmSender = XcmRouter;
+        type AssetIdToMultiLocation = AssetIdToMultiLocation;
+        type WeightInfo = ();
+    }
+}
+
+/// The destination parachain: a plain asset-holding chain that should end up
+/// crediting `ALICE` once the bridge's XCM program lands. It still carries
+/// `cosmos_pallet` in its runtime (construct_runtime requires every pallet
+/// named in the shared macro) but never calls it.
+pub mod destination_parachain {
+    use super::*;
+    use crate as cosmos_pallet;
+
+    impl_parachain_runtime!(
+        impl pallet_assets::Config for Runtime {
+            type RuntimeEvent = RuntimeEvent;
+            type Balance = Balance;
+            type AssetId = AssetId;
+            type AssetIdParameter = AssetId;
+            type Currency = Balances;
+            type CreateOrigin =
+                frame_support::traits::AsEnsureOriginWithArg<frame_system::EnsureSigned<AccountId>>;
+            type ForceOrigin = frame_system::EnsureRoot<AccountId>;
+            type AssetDeposit = ConstU32<0>;
+            type AssetAccountDeposit = ConstU32<0>;
+            type MetadataDepositBase = ConstU32<0>;
+            type MetadataDepositPerByte = ConstU32<0>;
+            type ApprovalDeposit = ConstU32<0>;
+            type StringLimit = ConstU32<50>;
+            type Freezer = ();
+            type Extra = ();
+            type CallbackHandle = ();
+            type WeightInfo = ();
+            type RemoveItemsLimit = ConstU32<5>;
+        }
+    );
+
+    pub struct AssetIdToMultiLocation;
+    impl sp_runtime::traits::Convert<AssetId, MultiLocation> for AssetIdToMultiLocation {
+        fn convert(asset_id: AssetId) -> MultiLocation {
+            MultiLocation::new(0, X1(GeneralIndex(asset_id as u128)))
+        }
+    }
+
+    parameter_types! {
+        pub const BridgePalletId: frame_support::PalletId = frame_support::PalletId(*b"py/cosmb");
+        pub const BridgeFee: Balance = 0;
+        pub const RelayerReward: Balance = 0;
+        pub const TrustingPeriod: u64 = 7 * 24 * 60 * 60;
+    }
+
+    impl cosmos_pallet::Config for Runtime {
+        type RuntimeEvent = RuntimeEvent;
+        type Currency = Balances;
+        type TimeProvider = frame_system::Pallet<Runtime>;
+        type TrustingPeriod = TrustingPeriod;
+        type PalletId = BridgePalletId;
+        type BridgeFee = BridgeFee;
+        type RelayerReward = RelayerReward;
+        type Assets = Assets;
+        type XcmSender = XcmRouter;
+        type AssetIdToMultiLocation = AssetIdToMultiLocation;
+        type WeightInfo = ();
+    }
+}
+
+decl_test_parachain! {
+    pub struct BridgeParachain {
+        Runtime = bridge_parachain::Runtime,
+        XcmpMessageHandler = bridge_parachain::MsgQueue,
+        DmpMessageHandler = bridge_parachain::MsgQueue,
+        new_ext = parachain_ext::<bridge_parachain::Runtime>(BRIDGE_PARA_ID),
+    }
+}
+
+decl_test_parachain! {
+    pub struct DestinationParachain {
+        Runtime = destination_parachain::Runtime,
+        XcmpMessageHandler = destination_parachain::MsgQueue,
+        DmpMessageHandler = destination_parachain::MsgQueue,
+        new_ext = parachain_ext::<destination_parachain::Runtime>(DESTINATION_PARA_ID),
+    }
+}
+
+decl_test_network! {
+    pub struct MockNet {
+        relay_chain = relay::Relay,
+        parachains = vec![
+            (BRIDGE_PARA_ID, BridgeParachain),
+            (DESTINATION_PARA_ID, DestinationParachain),
+        ],
+    }
+}
+
+/// A bare-bones relay chain: just enough `frame_system`/`pallet_xcm` to
+/// forward DMP messages between the two parachains above.
+pub mod relay {
+    use super::*;
+
+    construct_runtime!(
+        pub enum Runtime {
+            System: frame_system,
+            Balances: pallet_balances,
+            XcmPallet: pallet_xcm,
+        }
+    );
+
+    parameter_types! {
+        pub const BlockHashCount: u64 = 250;
+        pub RelayLocation: MultiLocation = MultiLocation::here();
+        pub UnitWeightCost: Weight = Weight::from_parts(1_000, 1_000);
+        pub const MaxInstructions: u32 = 100;
+    }
+
+    impl frame_system::Config for Runtime {
+        type BaseCallFilter = Everything;
+        type BlockWeights = ();
+        type BlockLength = ();
+        type RuntimeOrigin = RuntimeOrigin;
+        type RuntimeCall = RuntimeCall;
+        type Nonce = u64;
+        type Hash = H256;
+        type Hashing = sp_runtime::traits::BlakeTwo256;
+        type AccountId = AccountId;
+        type Lookup = IdentityLookup<AccountId>;
+        type Block = frame_system::mocking::MockBlock<Runtime>;
+        type RuntimeEvent = RuntimeEvent;
+        type BlockHashCount = BlockHashCount;
+        type DbWeight = ();
+        type Version = ();
+        type PalletInfo = PalletInfo;
+        type OnNewAccount = ();
+        type OnKilledAccount = ();
+        type AccountData = pallet_balances::AccountData<Balance>;
+        type SystemWeightInfo = ();
+        type SS58Prefix = ();
+        type OnSetCode = ();
+        type MaxConsumers = ConstU32<16>;
+    }
+
+    impl pallet_balances::Config for Runtime {
+        type MaxLocks = ConstU32<50>;
+        type MaxReserves = ConstU32<50>;
+        type ReserveIdentifier = [u8; 8];
+        type Balance = Balance;
+        type RuntimeEvent = RuntimeEvent;
+        type DustRemoval = ();
+        type ExistentialDeposit = ConstU32<1>;
+        type AccountStore = System;
+        type WeightInfo = ();
+        type FreezeIdentifier = ();
+        type MaxFreezes = ConstU32<0>;
+        type RuntimeHoldReason = RuntimeHoldReason;
+    }
+
+    pub type XcmRouter = xcm_simulator::RelayChainXcmRouter;
+
+    pub type LocationToAccountId = (
+        xcm_builder::ChildParachainConvertsVia<cumulus_primitives_core::ParaId, AccountId>,
+        AccountId32Aliases<RelayLocation, AccountId>,
+    );
+
+    pub type LocalAssetTransactor =
+        FungibleAdapter<Balances, IsConcrete<RelayLocation>, LocationToAccountId, AccountId, ()>;
+
+    pub struct XcmConfiguration;
+    impl XcmExecutorConfig for XcmConfiguration {
+        type RuntimeCall = RuntimeCall;
+        type XcmSender = XcmRouter;
+        type AssetTransactor = LocalAssetTransactor;
+        type OriginConverter =
+            SovereignSignedViaLocation<LocationToAccountId, RuntimeOrigin>;
+        type IsReserve = NativeAsset;
+        type IsTeleporter = ();
+        type UniversalLocation = xcm_builder::Parentage;
+        type Barrier = AllowUnpaidExecutionFrom<Everything>;
+        type Weigher = FixedWeightBounds<UnitWeightCost, RuntimeCall, MaxInstructions>;
+        type Trader = FixedRateOfFungible<RelayLocation, frame_support::traits::ConstU128<0>>;
+        type ResponseHandler = ();
+        type AssetTrap = ();
+        type AssetClaims = ();
+        type SubscriptionService = ();
+        type PalletInstancesInfo = ();
+        type MaxAssetsIntoHolding = ConstU32<64>;
+        type FeeManager = ();
+        type MessageExporter = ();
+        type UniversalAliases = Nothing;
+        type CallDispatcher = RuntimeCall;
+        type SafeCallFilter = Everything;
+        type Aliasers = Nothing;
+    }
+
+    impl pallet_xcm::Config for Runtime {
+        type RuntimeEvent = RuntimeEvent;
+        type SendXcmOrigin = xcm_builder::EnsureXcmOrigin<RuntimeOrigin, ()>;
+        type XcmRouter = XcmRouter;
+        type ExecuteXcmOrigin = xcm_builder::EnsureXcmOrigin<RuntimeOrigin, ()>;
+        type XcmExecuteFilter = Nothing;
+        type XcmExecutor = XcmExecutor<XcmConfiguration>;
+        type XcmTeleportFilter = Everything;
+        type XcmReserveTransferFilter = Everything;
+        type Weigher = FixedWeightBounds<UnitWeightCost, RuntimeCall, MaxInstructions>;
+        type UniversalLocation = xcm_builder::Parentage;
+        type RuntimeOrigin = RuntimeOrigin;
+        type RuntimeCall = RuntimeCall;
+        const VERSION_DISCOVERY_QUEUE_SIZE: u32 = 100;
+        type AdvertisedXcmVersion = pallet_xcm::CurrentXcmVersion;
+        type Currency = Balances;
+        type CurrencyMatcher = ();
+        type TrustedLockers = ();
+        type SovereignAccountOf = LocationToAccountId;
+        type MaxLockers = ConstU32<8>;
+        type WeightInfo = pallet_xcm::TestWeightInfo;
+        type AdminOrigin = frame_system::EnsureRoot<AccountId>;
+        type MaxRemoteLockConsumers = ConstU32<0>;
+        type RemoteLockConsumerIdentifier = ();
+    }
+
+    pub fn relay_ext() -> sp_io::TestExternalities {
+        let t = frame_system::GenesisConfig::<Runtime>::default()
+            .build_storage()
+            .unwrap();
+        let mut ext = sp_io::TestExternalities::new(t);
+        ext.execute_with(|| System::set_block_number(1));
+        ext
+    }
+}
+
+pub fn parachain_ext<Runtime>(para_id: u32) -> sp_io::TestExternalities
+where
+    Runtime: frame_system::Config<AccountId = AccountId> + pallet_balances::Config<Balance = Balance> + mock_msg_queue::Config,
+{
+    let mut t = frame_system::GenesisConfig::<Runtime>::default()
+        .build_storage()
+        .unwrap();
+    pallet_balances::GenesisConfig::<Runtime> {
+        balances: sp_std::vec![(ALICE, INITIAL_BALANCE)],
+    }
+    .assimilate_storage(&mut t)
+    .unwrap();
+
+    let mut ext = sp_io::TestExternalities::new(t);
+    ext.execute_with(|| {
+        frame_system::Pallet::<Runtime>::set_block_number(1u32.into());
+        mock_msg_queue::Pallet::<Runtime>::set_para_id(para_id);
+    });
+    ext
+}
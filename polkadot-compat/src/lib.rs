@@ -137,34 +137,396 @@ pub mod apple {
 
 /// Utility functions for multi-chain applications
 pub mod utils {
-    /// Validate a Cosmos address format
+    /// The network prefix `convert_address_format` encodes Polkadot-side
+    /// addresses under when none is otherwise specified (the "generic
+    /// substrate" prefix).
+    pub const DEFAULT_SS58_PREFIX: u8 = 42;
+
+    /// The human-readable part `convert_address_format` encodes Cosmos-side
+    /// addresses under when none is otherwise specified.
+    pub const DEFAULT_COSMOS_HRP: &str = "cosmos";
+
+    /// A from-scratch BIP-173 bech32 codec, used to validate and convert
+    /// Cosmos SDK addresses without pulling in an external crate.
+    pub mod bech32 {
+        const CHARSET: &[u8; 32] = b"qpzry9x8gf2tvdw0s3jn54khce6mua7l";
+        const CHECKSUM_LEN: usize = 6;
+
+        /// Reasons a bech32 string can fail to decode.
+        #[derive(Debug, Clone, Copy, PartialEq, Eq)]
+        pub enum Bech32Error {
+            /// No `1` separator between the human-readable part and the data part.
+            MissingSeparator,
+            /// The human-readable part is empty.
+            EmptyHrp,
+            /// A data-part character is outside the bech32 charset.
+            InvalidChar,
+            /// The data part is shorter than the checksum itself.
+            TooShort,
+            /// The checksum does not verify against the human-readable part.
+            InvalidChecksum,
+            /// The 5-bit groups did not regroup cleanly into 8-bit bytes.
+            InvalidPadding,
+        }
+
+        fn polymod(values: &[u8]) -> u32 {
+            const GEN: [u32; 5] = [0x3b6a57b2, 0x26508e6d, 0x1ea119fa, 0x3d4233dd, 0x2a1462b3];
+            let mut chk: u32 = 1;
+            for &v in values {
+                let top = chk >> 25;
+                chk = ((chk & 0x1ff_ffff) << 5) ^ (v as u32);
+                for (i, gen) in GEN.iter().enumerate() {
+                    if (top >> i) & 1 == 1 {
+                        chk ^= gen;
+                    }
+                }
+            }
+            chk
+        }
+
+        fn hrp_expand(hrp: &[u8]) -> Vec<u8> {
+            let mut v = Vec::with_capacity(hrp.len() * 2 + 1);
+            v.extend(hrp.iter().map(|c| c >> 5));
+            v.push(0);
+            v.extend(hrp.iter().map(|c| c & 31));
+            v
+        }
+
+        fn verify_checksum(hrp: &[u8], data: &[u8]) -> bool {
+            let mut values = hrp_expand(hrp);
+            values.extend_from_slice(data);
+            polymod(&values) == 1
+        }
+
+        fn create_checksum(hrp: &[u8], data: &[u8]) -> [u8; CHECKSUM_LEN] {
+            let mut values = hrp_expand(hrp);
+            values.extend_from_slice(data);
+            values.extend_from_slice(&[0u8; CHECKSUM_LEN]);
+            let polymod = polymod(&values) ^ 1;
+            let mut checksum = [0u8; CHECKSUM_LEN];
+            for (i, byte) in checksum.iter_mut().enumerate() {
+                *byte = ((polymod >> (5 * (CHECKSUM_LEN - 1 - i))) & 31) as u8;
+            }
+            checksum
+        }
+
+        fn convert_bits(data: &[u8], from_bits: u32, to_bits: u32, pad: bool) -> Option<Vec<u8>> {
+            let mut acc: u32 = 0;
+            let mut bits: u32 = 0;
+            let mut out = Vec::new();
+            let max_value = (1u32 << to_bits) - 1;
+            for &value in data {
+                if (value as u32) >> from_bits != 0 {
+                    return None;
+                }
+                acc = (acc << from_bits) | value as u32;
+                bits += from_bits;
+                while bits >= to_bits {
+                    bits -= to_bits;
+                    out.push(((acc >> bits) & max_value) as u8);
+                }
+            }
+            if pad {
+                if bits > 0 {
+                    out.push(((acc << (to_bits - bits)) & max_value) as u8);
+                }
+            } else if bits >= from_bits || ((acc << (to_bits - bits)) & max_value) != 0 {
+                return None;
+            }
+            Some(out)
+        }
+
+        /// Decodes a bech32 string into its human-readable part and data
+        /// payload (regrouped from 5-bit symbols back into bytes).
+        pub fn decode(input: &str) -> Result<(String, Vec<u8>), Bech32Error> {
+            let bytes = input.as_bytes();
+            let separator = bytes
+                .iter()
+                .rposition(|&c| c == b'1')
+                .ok_or(Bech32Error::MissingSeparator)?;
+            if separator == 0 {
+                return Err(Bech32Error::EmptyHrp);
+            }
+
+            let hrp: Vec<u8> = bytes[..separator]
+                .iter()
+                .map(|c| c.to_ascii_lowercase())
+                .collect();
+            let data_part = &bytes[separator + 1..];
+            if data_part.len() < CHECKSUM_LEN {
+                return Err(Bech32Error::TooShort);
+            }
+
+            let mut values = Vec::with_capacity(data_part.len());
+            for &c in data_part {
+                let v = CHARSET
+                    .iter()
+                    .position(|&x| x == c.to_ascii_lowercase())
+                    .ok_or(Bech32Error::InvalidChar)?;
+                values.push(v as u8);
+            }
+
+            if !verify_checksum(&hrp, &values) {
+                return Err(Bech32Error::InvalidChecksum);
+            }
+
+            let program = convert_bits(&values[..values.len() - CHECKSUM_LEN], 5, 8, false)
+                .ok_or(Bech32Error::InvalidPadding)?;
+            Ok((String::from_utf8_lossy(&hrp).into_owned(), program))
+        }
+
+        /// Encodes `program` bytes as a bech32 string under `hrp`.
+        pub fn encode(hrp: &str, program: &[u8]) -> Result<String, Bech32Error> {
+            let hrp = hrp.as_bytes();
+            let five_bit = convert_bits(program, 8, 5, true).ok_or(Bech32Error::InvalidPadding)?;
+            let checksum = create_checksum(hrp, &five_bit);
+
+            let mut out = String::with_capacity(hrp.len() + 1 + five_bit.len() + CHECKSUM_LEN);
+            out.push_str(&String::from_utf8_lossy(hrp));
+            out.push('1');
+            out.extend(five_bit.iter().map(|&v| CHARSET[v as usize] as char));
+            out.extend(checksum.iter().map(|&v| CHARSET[v as usize] as char));
+            Ok(out)
+        }
+    }
+
+    /// A minimal, dependency-free Blake2b implementation (RFC 7693),
+    /// parameterized on digest length, used by [`ss58`] for its checksum.
+    mod blake2b {
+        const IV: [u64; 8] = [
+            0x6a09e667f3bcc908, 0xbb67ae8584caa73b, 0x3c6ef372fe94f82b, 0xa54ff53a5f1d36f1,
+            0x510e527fade682d1, 0x9b05688c2b3e6c1f, 0x1f83d9abfb41bd6b, 0x5be0cd19137e2179,
+        ];
+
+        #[rustfmt::skip]
+        const SIGMA: [[usize; 16]; 12] = [
+            [0,1,2,3,4,5,6,7,8,9,10,11,12,13,14,15],
+            [14,10,4,8,9,15,13,6,1,12,0,2,11,7,5,3],
+            [11,8,12,0,5,2,15,13,10,14,3,6,7,1,9,4],
+            [7,9,3,1,13,12,11,14,2,6,5,10,4,0,15,8],
+            [9,0,5,7,2,4,10,15,14,1,11,12,6,8,3,13],
+            [2,12,6,10,0,11,8,3,4,13,7,5,15,14,1,9],
+            [12,5,1,15,14,13,4,10,0,7,6,3,9,2,8,11],
+            [13,11,7,14,12,1,3,9,5,0,15,4,8,6,2,10],
+            [6,15,14,9,11,3,0,8,12,2,13,7,1,4,10,5],
+            [10,2,8,4,7,6,1,5,15,11,9,14,3,12,13,0],
+            [0,1,2,3,4,5,6,7,8,9,10,11,12,13,14,15],
+            [14,10,4,8,9,15,13,6,1,12,0,2,11,7,5,3],
+        ];
+
+        fn g(v: &mut [u64; 16], a: usize, b: usize, c: usize, d: usize, x: u64, y: u64) {
+            v[a] = v[a].wrapping_add(v[b]).wrapping_add(x);
+            v[d] = (v[d] ^ v[a]).rotate_right(32);
+            v[c] = v[c].wrapping_add(v[d]);
+            v[b] = (v[b] ^ v[c]).rotate_right(24);
+            v[a] = v[a].wrapping_add(v[b]).wrapping_add(y);
+            v[d] = (v[d] ^ v[a]).rotate_right(16);
+            v[c] = v[c].wrapping_add(v[d]);
+            v[b] = (v[b] ^ v[c]).rotate_right(63);
+        }
+
+        fn compress(h: &mut [u64; 8], block: &[u8; 128], t: u128, last: bool) {
+            let mut m = [0u64; 16];
+            for (i, word) in m.iter_mut().enumerate() {
+                *word = u64::from_le_bytes(block[i * 8..i * 8 + 8].try_into().unwrap());
+            }
+            let mut v = [0u64; 16];
+            v[0..8].copy_from_slice(h);
+            v[8..16].copy_from_slice(&IV);
+            v[12] ^= (t & 0xFFFF_FFFF_FFFF_FFFF) as u64;
+            v[13] ^= (t >> 64) as u64;
+            if last {
+                v[14] = !v[14];
+            }
+            for sigma in SIGMA.iter() {
+                g(&mut v, 0, 4, 8, 12, m[sigma[0]], m[sigma[1]]);
+                g(&mut v, 1, 5, 9, 13, m[sigma[2]], m[sigma[3]]);
+                g(&mut v, 2, 6, 10, 14, m[sigma[4]], m[sigma[5]]);
+                g(&mut v, 3, 7, 11, 15, m[sigma[6]], m[sigma[7]]);
+                g(&mut v, 0, 5, 10, 15, m[sigma[8]], m[sigma[9]]);
+                g(&mut v, 1, 6, 11, 12, m[sigma[10]], m[sigma[11]]);
+                g(&mut v, 2, 7, 8, 13, m[sigma[12]], m[sigma[13]]);
+                g(&mut v, 3, 4, 9, 14, m[sigma[14]], m[sigma[15]]);
+            }
+            for i in 0..8 {
+                h[i] ^= v[i] ^ v[i + 8];
+            }
+        }
+
+        /// Hashes `input` to a digest of `out_len` bytes (1..=64), unkeyed.
+        pub fn hash(input: &[u8], out_len: usize) -> Vec<u8> {
+            let mut h = IV;
+            h[0] ^= 0x0101_0000 ^ (out_len as u64);
+
+            let mut t: u128 = 0;
+            let mut offset = 0;
+            loop {
+                let remaining = input.len() - offset;
+                let is_last = remaining <= 128;
+                let take = if is_last { remaining } else { 128 };
+                let mut block = [0u8; 128];
+                block[..take].copy_from_slice(&input[offset..offset + take]);
+                t += take as u128;
+                compress(&mut h, &block, t, is_last);
+                offset += take;
+                if is_last {
+                    break;
+                }
+            }
+
+            let mut out = Vec::with_capacity(64);
+            for word in h.iter() {
+                out.extend_from_slice(&word.to_le_bytes());
+            }
+            out.truncate(out_len);
+            out
+        }
+    }
+
+    /// A minimal base58 (Bitcoin alphabet) codec, used by [`ss58`].
+    mod base58 {
+        const ALPHABET: &[u8; 58] = b"123456789ABCDEFGHJKLMNPQRSTUVWXYZabcdefghijkmnopqrstuvwxyz";
+
+        pub fn encode(input: &[u8]) -> String {
+            let zeros = input.iter().take_while(|&&b| b == 0).count();
+            let mut digits: Vec<u8> = vec![0];
+            for &byte in input {
+                let mut carry = byte as u32;
+                for digit in digits.iter_mut() {
+                    carry += (*digit as u32) << 8;
+                    *digit = (carry % 58) as u8;
+                    carry /= 58;
+                }
+                while carry > 0 {
+                    digits.push((carry % 58) as u8);
+                    carry /= 58;
+                }
+            }
+            let mut out = String::with_capacity(zeros + digits.len());
+            out.extend(std::iter::repeat('1').take(zeros));
+            out.extend(digits.iter().rev().map(|&d| ALPHABET[d as usize] as char));
+            out
+        }
+
+        pub fn decode(input: &str) -> Option<Vec<u8>> {
+            let zeros = input.chars().take_while(|&c| c == '1').count();
+            let mut bytes: Vec<u8> = vec![0];
+            for c in input.chars() {
+                let value = ALPHABET.iter().position(|&a| a as char == c)? as u32;
+                let mut carry = value;
+                for byte in bytes.iter_mut() {
+                    carry += (*byte as u32) * 58;
+                    *byte = (carry & 0xff) as u8;
+                    carry >>= 8;
+                }
+                while carry > 0 {
+                    bytes.push((carry & 0xff) as u8);
+                    carry >>= 8;
+                }
+            }
+            let mut out = vec![0u8; zeros];
+            out.extend(bytes.iter().rev());
+            Some(out)
+        }
+    }
+
+    /// SS58 address encoding (base58 + a blake2b-512 "SS58PRE" checksum),
+    /// specialised to 32-byte substrate `AccountId32`s with a single-byte
+    /// network prefix (prefixes 0..=63, which covers every prefix in common
+    /// use today).
+    pub mod ss58 {
+        use super::{base58, blake2b};
+
+        const CHECKSUM_PREFIX: &[u8] = b"SS58PRE";
+
+        /// Reasons an SS58 string can fail to decode.
+        #[derive(Debug, Clone, Copy, PartialEq, Eq)]
+        pub enum Ss58Error {
+            /// Not valid base58.
+            InvalidBase58,
+            /// Decoded to something other than a 1-byte prefix + 32-byte
+            /// account id + 2-byte checksum.
+            InvalidLength,
+            /// The trailing checksum did not match.
+            InvalidChecksum,
+        }
+
+        fn checksum(prefixed_account: &[u8]) -> [u8; 2] {
+            let mut preimage = CHECKSUM_PREFIX.to_vec();
+            preimage.extend_from_slice(prefixed_account);
+            let digest = blake2b::hash(&preimage, 64);
+            [digest[0], digest[1]]
+        }
+
+        /// Encodes a 32-byte account id under `network_prefix`.
+        pub fn encode(account_id: &[u8; 32], network_prefix: u8) -> String {
+            let mut payload = Vec::with_capacity(1 + 32 + 2);
+            payload.push(network_prefix);
+            payload.extend_from_slice(account_id);
+            let sum = checksum(&payload);
+            payload.extend_from_slice(&sum);
+            base58::encode(&payload)
+        }
+
+        /// Decodes an SS58 address into its account id and network prefix.
+        pub fn decode(address: &str) -> Result<([u8; 32], u8), Ss58Error> {
+            let data = base58::decode(address).ok_or(Ss58Error::InvalidBase58)?;
+            if data.len() != 35 {
+                return Err(Ss58Error::InvalidLength);
+            }
+            let sum = checksum(&data[..33]);
+            if data[33..35] != sum {
+                return Err(Ss58Error::InvalidChecksum);
+            }
+            let mut account_id = [0u8; 32];
+            account_id.copy_from_slice(&data[1..33]);
+            Ok((account_id, data[0]))
+        }
+    }
+
+    /// Derives a substrate `AccountId32` from a Cosmos address's decoded
+    /// bech32 payload. This is one-way: the Cosmos payload is itself a hash
+    /// of a secp256k1 public key, so there is no real public key to carry
+    /// through, only its bech32-decoded bytes rehashed under a domain tag.
+    fn derive_account_id_from_cosmos_payload(payload: &[u8]) -> [u8; 32] {
+        let mut preimage = b"cosmos:".to_vec();
+        preimage.extend_from_slice(payload);
+        let digest = blake2b::hash(&preimage, 32);
+        let mut account_id = [0u8; 32];
+        account_id.copy_from_slice(&digest);
+        account_id
+    }
+
+    /// Validate a Cosmos address format: a well-formed, checksummed bech32
+    /// string.
     pub fn validate_cosmos_address(address: &str) -> bool {
-        // Basic validation - in real implementation would be more comprehensive
-        address.len() >= 20 && address.len() <= 255
+        bech32::decode(address).is_ok()
     }
 
-    /// Validate a Polkadot address format  
+    /// Validate a Polkadot address format: a well-formed, checksummed SS58
+    /// string.
     pub fn validate_polkadot_address(address: &str) -> bool {
-        // Basic validation - in real implementation would use proper SS58 validation
-        address.len() >= 32 && address.len() <= 64
+        ss58::decode(address).is_ok()
     }
 
-    /// Convert between different address formats
+    /// Convert between Cosmos bech32 and Polkadot SS58 address formats.
+    ///
+    /// Cosmos -> Polkadot derives a real `AccountId32` from the address's
+    /// decoded payload (see [`derive_account_id_from_cosmos_payload`]) and
+    /// SS58-encodes it under [`DEFAULT_SS58_PREFIX`]. Polkadot -> Cosmos is
+    /// only a best-effort inverse: it bech32-encodes the SS58-decoded
+    /// account id bytes directly under [`DEFAULT_COSMOS_HRP`], since the
+    /// forward direction's hash cannot be undone.
     pub fn convert_address_format(address: &str, target_format: &str) -> Option<String> {
         match target_format {
-            "cosmos" => {
-                if validate_cosmos_address(address) {
-                    Some(address.to_string())
-                } else {
-                    None
-                }
-            }
             "polkadot" => {
-                if validate_polkadot_address(address) {
-                    Some(address.to_string())
-                } else {
-                    None
-                }
+                let (_hrp, payload) = bech32::decode(address).ok()?;
+                let account_id = derive_account_id_from_cosmos_payload(&payload);
+                Some(ss58::encode(&account_id, DEFAULT_SS58_PREFIX))
+            }
+            "cosmos" => {
+                let (account_id, _prefix) = ss58::decode(address).ok()?;
+                bech32::encode(DEFAULT_COSMOS_HRP, &account_id).ok()
             }
             _ => None,
         }
@@ -274,6 +636,42 @@ pub mod ffi {
         }
     }
 
+    /// Convert an address between Cosmos bech32 and Polkadot SS58 formats
+    /// (FFI function for Go). Returns a Rust-allocated string the caller
+    /// must release with `free_rust_string`, or null if `address` is not
+    /// valid in its source format or `target_format` is unrecognized.
+    #[no_mangle]
+    pub extern "C" fn convert_address_format(
+        address: *const c_char,
+        target_format: *const c_char,
+    ) -> *mut c_char {
+        if address.is_null() || target_format.is_null() {
+            return std::ptr::null_mut();
+        }
+
+        let address_str = unsafe {
+            match CStr::from_ptr(address).to_str() {
+                Ok(s) => s,
+                Err(_) => return std::ptr::null_mut(),
+            }
+        };
+
+        let target_format_str = unsafe {
+            match CStr::from_ptr(target_format).to_str() {
+                Ok(s) => s,
+                Err(_) => return std::ptr::null_mut(),
+            }
+        };
+
+        match utils::convert_address_format(address_str, target_format_str) {
+            Some(converted) => match CString::new(converted) {
+                Ok(c_string) => c_string.into_raw(),
+                Err(_) => std::ptr::null_mut(),
+            },
+            None => std::ptr::null_mut(),
+        }
+    }
+
     /// Free a Rust-allocated string (FFI function for Go)
     #[no_mangle]
     pub extern "C" fn free_rust_string(ptr: *mut c_char) {
@@ -291,13 +689,30 @@ mod tests {
 
     #[test]
     fn test_address_validation() {
-        assert!(utils::validate_cosmos_address("cosmos1234567890123456789"));
+        let cosmos_address = utils::bech32::encode("cosmos", &[0u8; 20]).unwrap();
+        assert!(utils::validate_cosmos_address(&cosmos_address));
         assert!(!utils::validate_cosmos_address("short"));
-        
+        assert!(!utils::validate_cosmos_address("cosmos1234567890123456789"));
+
         assert!(utils::validate_polkadot_address("5GrwvaEF5zXb26Fz9rcQpDWS57CtERHpNehXCPcNoHGKutQY"));
         assert!(!utils::validate_polkadot_address("short"));
     }
 
+    #[test]
+    fn test_address_conversion_yields_valid_addresses_in_both_formats() {
+        let cosmos_address = utils::bech32::encode("cosmos", &[1u8; 20]).unwrap();
+
+        let polkadot_address =
+            utils::convert_address_format(&cosmos_address, "polkadot").expect("derives an AccountId32");
+        assert!(utils::validate_polkadot_address(&polkadot_address));
+
+        let back_to_cosmos = utils::convert_address_format(&polkadot_address, "cosmos")
+            .expect("bech32-encodes the decoded account id");
+        assert!(utils::validate_cosmos_address(&back_to_cosmos));
+
+        assert_eq!(utils::convert_address_format(&cosmos_address, "unknown"), None);
+    }
+
     #[test]
     fn test_cross_chain_transaction() {
         let tx = CrossChainTransaction {